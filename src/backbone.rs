@@ -0,0 +1,302 @@
+//! Backbone (φ/ψ/ω) and side-chain (χ1-χ4) torsion angles for protein chains.
+//!
+//! This module turns the crate's four-point [`dihedral`](crate::dihedral) primitive into a
+//! small Ramachandran/rotamer analysis toolkit: given a chain of [`Residue`]s, each holding its
+//! atoms keyed by PDB atom name, [`torsions`] computes the standard backbone and side-chain
+//! torsion angles residue by residue.
+
+use crate::{dihedral, norm, v, Float, Point3D};
+use std::collections::HashMap;
+
+/// The maximum C-N distance, in Å, for two consecutive residues to be considered covalently
+/// bonded. Residues further apart than this (or missing the relevant atoms) are treated as a
+/// chain break, and torsions that would cross the break are `None`.
+const MAX_PEPTIDE_BOND_LENGTH: Float = 2.0;
+
+/// A single residue along a polypeptide chain.
+///
+/// `name` is the three-letter amino-acid code (e.g. `"LEU"`), used to look up which atoms form
+/// the side-chain χ torsions. `atoms` maps PDB atom names (e.g. `"CA"`, `"CB"`) to coordinates;
+/// residues are free to omit atoms they don't have resolved, and any torsion that needs a
+/// missing atom is simply left out of the result.
+#[derive(Debug, Clone, Default)]
+pub struct Residue {
+    pub name: String,
+    pub atoms: HashMap<String, Point3D>,
+}
+
+impl Residue {
+    /// Creates an empty residue of the given amino-acid type.
+    pub fn new(name: impl Into<String>) -> Self {
+        Residue {
+            name: name.into(),
+            atoms: HashMap::new(),
+        }
+    }
+
+    /// Adds an atom's coordinate, returning `self` for chaining.
+    pub fn with_atom(mut self, name: impl Into<String>, coord: Point3D) -> Self {
+        self.atoms.insert(name.into(), coord);
+        self
+    }
+
+    fn atom(&self, name: &str) -> Option<Point3D> {
+        self.atoms.get(name).copied()
+    }
+}
+
+/// The backbone and side-chain torsion angles of a single residue, in radians.
+///
+/// Each field is `None` when the atoms it depends on are missing, or when the bond to a
+/// neighbouring residue it depends on is absent (a chain break).
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct Torsions {
+    /// C(i-1)-N(i)-CA(i)-C(i)
+    pub phi: Option<Float>,
+    /// N(i)-CA(i)-C(i)-N(i+1)
+    pub psi: Option<Float>,
+    /// CA(i)-C(i)-N(i+1)-CA(i+1)
+    pub omega: Option<Float>,
+    pub chi1: Option<Float>,
+    pub chi2: Option<Float>,
+    pub chi3: Option<Float>,
+    pub chi4: Option<Float>,
+}
+
+/// Computes the φ/ψ/ω backbone torsions and χ1-χ4 side-chain torsions for every residue in
+/// `residues`, in chain order.
+///
+/// # Examples
+///
+/// ```
+/// use dihedral::backbone::{torsions, Residue};
+///
+/// let prev = Residue::new("ALA").with_atom("C", [24.5, 14.0, 31.0]);
+/// let leu = Residue::new("LEU")
+///     .with_atom("N", [24.969, 13.428, 30.692])
+///     .with_atom("CA", [24.044, 12.661, 29.808])
+///     .with_atom("C", [22.785, 13.482, 29.543])
+///     .with_atom("CB", [23.672, 11.328, 30.466])
+///     .with_atom("CG", [22.881, 10.326, 29.620])
+///     .with_atom("CD1", [23.691, 9.935, 28.389]);
+///
+/// let result = torsions(&[prev, leu]);
+/// let leu_torsions = &result[1];
+///
+/// assert!((leu_torsions.phi.unwrap().to_degrees() - 16.36645).abs() < 1E-2);
+/// // no following residue, so psi/omega can't be computed
+/// assert_eq!(leu_torsions.psi, None);
+/// assert_eq!(leu_torsions.omega, None);
+/// assert!((leu_torsions.chi1.unwrap().to_degrees() - (-171.94319)).abs() < 1E-2);
+/// assert!((leu_torsions.chi2.unwrap().to_degrees() - 60.82226).abs() < 1E-2);
+/// // LEU only has chi1/chi2
+/// assert_eq!(leu_torsions.chi3, None);
+/// ```
+pub fn torsions(residues: &[Residue]) -> Vec<Torsions> {
+    residues
+        .iter()
+        .enumerate()
+        .map(|(i, residue)| {
+            let prev = i.checked_sub(1).and_then(|j| residues.get(j));
+            let next = residues.get(i + 1);
+
+            let phi = prev.and_then(|prev| phi(prev, residue));
+            let psi = next.and_then(|next| psi(residue, next));
+            let omega = next.and_then(|next| omega(residue, next));
+            let [chi1, chi2, chi3, chi4] = chi_angles(residue);
+
+            Torsions {
+                phi,
+                psi,
+                omega,
+                chi1,
+                chi2,
+                chi3,
+                chi4,
+            }
+        })
+        .collect()
+}
+
+fn bonded(c: Point3D, n: Point3D) -> bool {
+    norm(v(c, n)) < MAX_PEPTIDE_BOND_LENGTH
+}
+
+fn phi(prev: &Residue, residue: &Residue) -> Option<Float> {
+    let (c_prev, n, ca, c) = (
+        prev.atom("C")?,
+        residue.atom("N")?,
+        residue.atom("CA")?,
+        residue.atom("C")?,
+    );
+    bonded(c_prev, n).then(|| dihedral([c_prev, n, ca, c]))
+}
+
+fn psi(residue: &Residue, next: &Residue) -> Option<Float> {
+    let (n, ca, c, n_next) = (
+        residue.atom("N")?,
+        residue.atom("CA")?,
+        residue.atom("C")?,
+        next.atom("N")?,
+    );
+    bonded(c, n_next).then(|| dihedral([n, ca, c, n_next]))
+}
+
+fn omega(residue: &Residue, next: &Residue) -> Option<Float> {
+    let (ca, c, n_next, ca_next) = (
+        residue.atom("CA")?,
+        residue.atom("C")?,
+        next.atom("N")?,
+        next.atom("CA")?,
+    );
+    bonded(c, n_next).then(|| dihedral([ca, c, n_next, ca_next]))
+}
+
+fn chi_angles(residue: &Residue) -> [Option<Float>; 4] {
+    let mut chis = [None; 4];
+    for (chi, names) in chis.iter_mut().zip(chi_atom_names(&residue.name).iter()) {
+        *chi = names.and_then(|[a, b, c, d]| {
+            Some(dihedral([
+                residue.atom(a)?,
+                residue.atom(b)?,
+                residue.atom(c)?,
+                residue.atom(d)?,
+            ]))
+        });
+    }
+    chis
+}
+
+/// The atom-name quartets defining χ1-χ4 for each amino acid, in the order used by the PDB
+/// rotamer libraries. `None` entries mean that χ doesn't exist for the residue type.
+fn chi_atom_names(residue_name: &str) -> [Option<[&'static str; 4]>; 4] {
+    match residue_name.to_ascii_uppercase().as_str() {
+        "ARG" => [
+            Some(["N", "CA", "CB", "CG"]),
+            Some(["CA", "CB", "CG", "CD"]),
+            Some(["CB", "CG", "CD", "NE"]),
+            Some(["CG", "CD", "NE", "CZ"]),
+        ],
+        "ASN" | "ASP" => [
+            Some(["N", "CA", "CB", "CG"]),
+            Some(["CA", "CB", "CG", "OD1"]),
+            None,
+            None,
+        ],
+        "CYS" => [Some(["N", "CA", "CB", "SG"]), None, None, None],
+        "GLN" | "GLU" => [
+            Some(["N", "CA", "CB", "CG"]),
+            Some(["CA", "CB", "CG", "CD"]),
+            Some(["CB", "CG", "CD", "OE1"]),
+            None,
+        ],
+        "HIS" => [
+            Some(["N", "CA", "CB", "CG"]),
+            Some(["CA", "CB", "CG", "ND1"]),
+            None,
+            None,
+        ],
+        "ILE" => [
+            Some(["N", "CA", "CB", "CG1"]),
+            Some(["CA", "CB", "CG1", "CD1"]),
+            None,
+            None,
+        ],
+        "LEU" => [
+            Some(["N", "CA", "CB", "CG"]),
+            Some(["CA", "CB", "CG", "CD1"]),
+            None,
+            None,
+        ],
+        "LYS" => [
+            Some(["N", "CA", "CB", "CG"]),
+            Some(["CA", "CB", "CG", "CD"]),
+            Some(["CB", "CG", "CD", "CE"]),
+            Some(["CG", "CD", "CE", "NZ"]),
+        ],
+        "MET" => [
+            Some(["N", "CA", "CB", "CG"]),
+            Some(["CA", "CB", "CG", "SD"]),
+            Some(["CB", "CG", "SD", "CE"]),
+            None,
+        ],
+        "PHE" | "TYR" | "TRP" => [
+            Some(["N", "CA", "CB", "CG"]),
+            Some(["CA", "CB", "CG", "CD1"]),
+            None,
+            None,
+        ],
+        "PRO" => [
+            Some(["N", "CA", "CB", "CG"]),
+            Some(["CA", "CB", "CG", "CD"]),
+            None,
+            None,
+        ],
+        "SER" => [Some(["N", "CA", "CB", "OG"]), None, None, None],
+        "THR" => [Some(["N", "CA", "CB", "OG1"]), None, None, None],
+        "VAL" => [Some(["N", "CA", "CB", "CG1"]), None, None, None],
+        // ALA and GLY have no side-chain torsion; anything unrecognised is treated the same way.
+        _ => [None, None, None, None],
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sample_chain() -> Vec<Residue> {
+        let prev = Residue::new("ALA").with_atom("C", [24.5, 14.0, 31.0]);
+        let leu = Residue::new("LEU")
+            .with_atom("N", [24.969, 13.428, 30.692])
+            .with_atom("CA", [24.044, 12.661, 29.808])
+            .with_atom("C", [22.785, 13.482, 29.543])
+            .with_atom("CB", [23.672, 11.328, 30.466])
+            .with_atom("CG", [22.881, 10.326, 29.620])
+            .with_atom("CD1", [23.691, 9.935, 28.389])
+            .with_atom("CD2", [22.557, 9.096, 30.459]);
+        vec![prev, leu]
+    }
+
+    #[test]
+    fn test_phi_and_chi1() {
+        let result = torsions(&sample_chain());
+        let leu = &result[1];
+        assert!((leu.phi.unwrap().to_degrees() - 16.36645).abs() < 1E-2);
+        assert!((leu.chi1.unwrap().to_degrees() - (-171.94319)).abs() < 1E-2);
+        assert_eq!(leu.psi, None);
+        assert_eq!(leu.omega, None);
+    }
+
+    #[test]
+    fn test_chi2_uses_correct_atoms() {
+        let result = torsions(&sample_chain());
+        let leu = &result[1];
+        assert!((leu.chi2.unwrap().to_degrees() - 60.82226).abs() < 1E-2);
+    }
+
+    #[test]
+    fn test_missing_atoms_and_chain_breaks_are_none() {
+        // a lone residue has no neighbours, so phi/psi/omega are all None
+        let ala = Residue::new("ALA").with_atom("CA", [0.0, 0.0, 0.0]);
+        let result = torsions(&[ala]);
+        assert_eq!(result[0].phi, None);
+        assert_eq!(result[0].psi, None);
+        assert_eq!(result[0].omega, None);
+
+        // a gap far larger than a peptide bond is treated as a chain break
+        let first = Residue::new("ALA").with_atom("C", [0.0, 0.0, 0.0]);
+        let second = Residue::new("ALA")
+            .with_atom("N", [100.0, 100.0, 100.0])
+            .with_atom("CA", [101.0, 100.0, 100.0])
+            .with_atom("C", [102.0, 101.0, 100.0]);
+        let result = torsions(&[first, second]);
+        assert_eq!(result[1].phi, None);
+    }
+
+    #[test]
+    fn test_residues_without_side_chain_have_no_chi() {
+        let gly = Residue::new("GLY").with_atom("CA", [0.0, 0.0, 0.0]);
+        let result = torsions(&[gly]);
+        assert_eq!(result[0].chi1, None);
+    }
+}
@@ -4,10 +4,17 @@
 //! [![crates.io](https://img.shields.io/crates/v/dihedral.svg)](https://crates.io/crates/dihedral)
 //! [![crates.io](https://img.shields.io/crates/l/dihedral.svg)](https://crates.io/crates/dihedral)
 //!
-//!  This crate provides functions for working with dihedral angles. Currently, there are two functions:
+//!  This crate provides functions for working with dihedral angles and bond angles. Currently, there are eight functions:
 //!
 //! - `dihedral` calculates the dihedral angle in the range -π to π in accordance with biochemistry textbooks (see also: https://en.wikipedia.org/wiki/Dihedral_angle#In_stereochemistry)
 //! - `dihedral_unsigned` ignores the direction of rotation and outputs the angle within the range 0 to π. This function is faster than the above signed version.
+//! - `dihedral_rad` and `dihedral_deg` are like `dihedral` but return a strongly-typed `Rad` or `Deg` so callers can't mix up the unit.
+//! - `dihedral_chain` and `dihedral_chain_into` calculate the dihedral angle of every four consecutive points in a chain in a single pass, reusing shared bond vectors and cross products.
+//! - `angle` calculates the bond angle, in the range 0 to π, at the central point of three ordered coordinates.
+//! - `signed_angle` is the general primitive that `dihedral` is built on: the signed angle between two vectors measured right-handed about an arbitrary axis.
+//!
+//! The [`backbone`] module builds on these primitives to compute the standard protein backbone
+//! (φ/ψ/ω) and side-chain (χ1-χ4) torsion angles for a chain of residues.
 //!
 //! If you want to use `f32` instead of `f64` for calculation, you can add `dihedral = {version = "*", features = ["f32"]}` to your `Cargo.toml`.
 //!
@@ -16,6 +23,11 @@
 //! - https://math.stackexchange.com/a/47084
 //! - https://en.wikipedia.org/wiki/Dihedral_angle#In_stereochemistry
 
+pub mod backbone;
+mod units;
+
+pub use units::{Deg, Rad};
+
 #[cfg(not(feature = "f32"))]
 type Float = f64;
 #[cfg(feature = "f32")]
@@ -49,10 +61,38 @@ type Point3D = [Float; 3];
 #[inline]
 pub fn dihedral([a, b, c, d]: [Point3D; 4]) -> Float {
     let (a, b, c) = (v(a, b), v(b, c), v(c, d));
-    let (r, s) = (u(cross(a, b)), u(cross(b, c)));
-    let t = cross(r, u(b));
-    let (x, y) = (dot(r, s), dot(s, t));
-    -y.atan2(x)
+    signed_angle(cross(a, b), cross(b, c), b)
+}
+
+/// Calculates the signed angle from `v1` to `v2`, in the range -π to π, measured right-handed
+/// about `axis`.
+///
+/// `axis` does not need to be a unit vector, but its direction determines the sign of the
+/// result; reversing `axis` negates the returned angle. `v1` and `v2` do not need to be unit
+/// vectors. The result is the angle between their projections onto the plane perpendicular to
+/// `axis` only when `v1` and `v2` are themselves perpendicular to `axis`; their components along
+/// `axis` are not removed and still contribute to `dot(v1, v2)`.
+///
+/// This is a generalisation of [`dihedral`], which is equivalent to
+/// `signed_angle(n1, n2, central_bond)` where `n1` and `n2` are the normals of the two planes
+/// defined by the four dihedral points.
+///
+/// # Examples
+///
+/// ```
+/// use dihedral::signed_angle;
+///
+/// let v1 = [1.0, 0.0, 0.0];
+/// let v2 = [0.0, 1.0, 0.0];
+/// let axis = [0.0, 0.0, 1.0];
+///
+/// assert!((signed_angle(v1, v2, axis).to_degrees() - 90.0).abs() < 1E-2);
+/// assert!((signed_angle(v2, v1, axis).to_degrees() - (-90.0)).abs() < 1E-2);
+/// ```
+#[inline]
+pub fn signed_angle(v1: Vector3D, v2: Vector3D, axis: Vector3D) -> Float {
+    let axis = u(axis);
+    dot(axis, cross(v1, v2)).atan2(dot(v1, v2))
 }
 
 /// Calculates the unsigned dihedral angle, in the range 0 to π, of the four ordered coordinates
@@ -83,6 +123,129 @@ pub fn dihedral_unsigned([a, b, c, d]: [Point3D; 4]) -> Float {
     (dot(r, s) / (norm(r) * norm(s))).acos()
 }
 
+/// Calculates the dihedral angle, as a strongly-typed [`Rad`], of the four ordered coordinates.
+///
+/// This avoids the unit-confusion bug of forgetting whether a plain [`Float`] is in radians or
+/// degrees; see [`dihedral_deg`] for the degrees counterpart.
+///
+/// # Examples
+///
+/// ```
+/// use dihedral::{dihedral_rad, Rad};
+///
+/// let P0 = [24.969, 13.428, 30.692]; // N
+/// let P1 = [24.044, 12.661, 29.808]; // CA
+/// let P2 = [22.785, 13.482, 29.543]; // C
+/// let P3 = [21.951, 13.670, 30.431]; // O
+///
+/// let Rad(angle) = dihedral_rad([P0, P1, P2, P3]);
+/// assert!((angle.to_degrees() - (-71.21515)).abs() < 1E-2);
+/// ```
+#[inline]
+pub fn dihedral_rad(points: [Point3D; 4]) -> Rad {
+    Rad(dihedral(points))
+}
+
+/// Calculates the dihedral angle, as a strongly-typed [`Deg`], of the four ordered coordinates.
+///
+/// See [`dihedral_rad`] for the radians counterpart.
+///
+/// # Examples
+///
+/// ```
+/// use dihedral::{dihedral_deg, Deg};
+///
+/// let P0 = [24.969, 13.428, 30.692]; // N
+/// let P1 = [24.044, 12.661, 29.808]; // CA
+/// let P2 = [22.785, 13.482, 29.543]; // C
+/// let P3 = [21.951, 13.670, 30.431]; // O
+///
+/// let Deg(angle) = dihedral_deg([P0, P1, P2, P3]);
+/// assert!((angle - (-71.21515)).abs() < 1E-2);
+/// ```
+#[inline]
+pub fn dihedral_deg(points: [Point3D; 4]) -> Deg {
+    dihedral_rad(points).into()
+}
+
+/// Calculates the dihedral angle, in the range -π to π, of every four consecutive points in
+/// `points`, i.e. the dihedral angle of `points[i..i + 4]` for `i` in `0..points.len() - 3`.
+///
+/// This reuses the bond vectors and cross products shared between consecutive windows instead
+/// of recomputing them from scratch for each window, which is roughly twice as fast as calling
+/// [`dihedral`] in a loop for long chains. See [`dihedral_chain_into`] for an allocation-free
+/// version.
+///
+/// # Examples
+///
+/// ```
+/// use dihedral::dihedral_chain;
+///
+/// let P0 = [24.969, 13.428, 30.692]; // N
+/// let P1 = [24.044, 12.661, 29.808]; // CA
+/// let P2 = [22.785, 13.482, 29.543]; // C
+/// let P3 = [21.951, 13.670, 30.431]; // O
+/// let P4 = [23.672, 11.328, 30.466]; // CB
+/// let P5 = [22.881, 10.326, 29.620]; // CG
+/// let P6 = [23.691, 9.935, 28.389]; // CD1
+/// let P7 = [22.557, 9.096, 30.459]; // CD2
+///
+/// let angles = dihedral_chain(&[P0, P1, P2, P3, P4, P5, P6, P7]);
+/// assert!((angles[0].to_degrees() - (-71.21515)).abs() < 1E-2);
+/// assert!((angles[4].to_degrees() - (120.92858)).abs() < 1E-2);
+/// ```
+pub fn dihedral_chain(points: &[Point3D]) -> Vec<Float> {
+    let mut angles = vec![0.0; points.len().saturating_sub(3)];
+    dihedral_chain_into(points, &mut angles);
+    angles
+}
+
+/// Allocation-free version of [`dihedral_chain`] that writes the `points.len() - 3` dihedral
+/// angles into `out`.
+///
+/// # Panics
+///
+/// Panics if `out.len() != points.len().saturating_sub(3)`.
+pub fn dihedral_chain_into(points: &[Point3D], out: &mut [Float]) {
+    let n = points.len();
+    assert_eq!(out.len(), n.saturating_sub(3));
+    if n < 4 {
+        return;
+    }
+
+    let bonds: Vec<Vector3D> = (0..n - 1).map(|i| v(points[i], points[i + 1])).collect();
+    let crosses: Vec<Vector3D> = (0..bonds.len() - 1)
+        .map(|i| cross(bonds[i], bonds[i + 1]))
+        .collect();
+
+    for (i, angle) in out.iter_mut().enumerate() {
+        let (r, s) = (u(crosses[i]), u(crosses[i + 1]));
+        let t = cross(r, u(bonds[i + 1]));
+        let (x, y) = (dot(r, s), dot(s, t));
+        *angle = -y.atan2(x);
+    }
+}
+
+/// Calculates the bond angle, in the range 0 to π, at the central point `b` of the three
+/// ordered coordinates `a`, `b`, `c`.
+///
+/// # Examples
+///
+/// ```
+/// use dihedral::angle;
+///
+/// let P0 = [24.969, 13.428, 30.692]; // N
+/// let P1 = [24.044, 12.661, 29.808]; // CA
+/// let P2 = [22.785, 13.482, 29.543]; // C
+///
+/// assert!((angle([P0, P1, P2]).to_degrees() - 109.74388).abs() < 1E-2);
+/// ```
+#[inline]
+pub fn angle([a, b, c]: [Point3D; 3]) -> Float {
+    let (a, c) = (v(b, a), v(b, c));
+    (dot(a, c) / (norm(a) * norm(c))).acos()
+}
+
 /// Norm (length) of a 3D vector
 #[inline(always)]
 fn norm(a: Vector3D) -> Float {
@@ -140,6 +303,63 @@ mod tests {
         assert!((dihedral([P1, P4, P5, P7]).to_degrees() - (-177.63641)).abs() < 1E-2);
     }
 
+    #[test]
+    fn test_signed_angle() {
+        let v1 = [1.0, 0.0, 0.0];
+        let v2 = [0.0, 1.0, 0.0];
+        let axis = [0.0, 0.0, 1.0];
+        assert!((signed_angle(v1, v2, axis) - std::f64::consts::FRAC_PI_2 as Float).abs() < 1E-2);
+        assert!(
+            (signed_angle(v2, v1, axis) - (-std::f64::consts::FRAC_PI_2 as Float)).abs() < 1E-2
+        );
+
+        // dihedral is a special case of signed_angle on the two plane normals about the
+        // central bond.
+        let (bond1, bond2, bond3) = (v(P0, P1), v(P1, P2), v(P2, P3));
+        let (n1, n2) = (cross(bond1, bond2), cross(bond2, bond3));
+        assert!((signed_angle(n1, n2, bond2) - dihedral([P0, P1, P2, P3])).abs() < 1E-2);
+    }
+
+    #[test]
+    fn test_dihedral_rad_deg() {
+        let Rad(rad) = dihedral_rad([P0, P1, P2, P3]);
+        assert_eq!(rad, dihedral([P0, P1, P2, P3]));
+
+        let Deg(deg) = dihedral_deg([P0, P1, P2, P3]);
+        assert!((deg - (-71.21515)).abs() < 1E-2);
+    }
+
+    #[test]
+    fn test_dihedral_chain() {
+        let points = [P0, P1, P2, P3, P4, P5, P6, P7];
+        let angles = dihedral_chain(&points);
+        let expected = [
+            dihedral([P0, P1, P2, P3]),
+            dihedral([P1, P2, P3, P4]),
+            dihedral([P2, P3, P4, P5]),
+            dihedral([P3, P4, P5, P6]),
+            dihedral([P4, P5, P6, P7]),
+        ];
+        assert_eq!(angles.len(), expected.len());
+        for (a, e) in angles.iter().zip(expected.iter()) {
+            assert!((a.to_degrees() - e.to_degrees()).abs() < 1E-2);
+        }
+    }
+
+    #[test]
+    fn test_dihedral_chain_into() {
+        let points = [P0, P1, P2, P3, P4, P5, P6, P7];
+        let mut out = [0.0; 5];
+        dihedral_chain_into(&points, &mut out);
+        assert_eq!(out.to_vec(), dihedral_chain(&points));
+    }
+
+    #[test]
+    fn test_angle() {
+        assert!((angle([P0, P1, P2]).to_degrees() - 109.74388).abs() < 1E-2);
+        assert!((angle([P1, P4, P5]).to_degrees() - 117.21439).abs() < 1E-2);
+    }
+
     #[test]
     fn test_dihedral_unsigned() {
         println!("{}", dihedral_unsigned([P0, P1, P4, P5]).to_degrees());
@@ -0,0 +1,52 @@
+//! Strongly-typed angle units, so callers don't have to remember whether a returned
+//! [`Float`](crate::Float) is in radians or degrees.
+
+use crate::Float;
+
+/// An angle in radians.
+#[derive(Debug, Clone, Copy, PartialEq, PartialOrd)]
+pub struct Rad(pub Float);
+
+/// An angle in degrees.
+#[derive(Debug, Clone, Copy, PartialEq, PartialOrd)]
+pub struct Deg(pub Float);
+
+impl From<Rad> for Deg {
+    fn from(rad: Rad) -> Self {
+        Deg(rad.0.to_degrees())
+    }
+}
+
+impl From<Deg> for Rad {
+    fn from(deg: Deg) -> Self {
+        Rad(deg.0.to_radians())
+    }
+}
+
+impl From<Float> for Rad {
+    fn from(value: Float) -> Self {
+        Rad(value)
+    }
+}
+
+impl From<Rad> for Float {
+    fn from(rad: Rad) -> Self {
+        rad.0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_rad_deg_conversions() {
+        let rad = Rad(std::f64::consts::PI as Float);
+        let deg: Deg = rad.into();
+        assert!((deg.0 - 180.0).abs() < 1E-2);
+
+        let deg = Deg(180.0);
+        let rad: Rad = deg.into();
+        assert!((rad.0 - std::f64::consts::PI as Float).abs() < 1E-2);
+    }
+}